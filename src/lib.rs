@@ -28,6 +28,44 @@ impl<A: Clone + fmt::Display> fmt::Display for Matrix<A> {
     }
 }
 
+impl<A> std::ops::Index<(usize, usize)> for Matrix<A> {
+    type Output = A;
+
+    fn index(&self, (row, col): (usize, usize)) -> &A {
+        &self.val[row * self.cols + col]
+    }
+}
+
+impl<A> std::ops::IndexMut<(usize, usize)> for Matrix<A> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut A {
+        &mut self.val[row * self.cols + col]
+    }
+}
+
+// Builds a Matrix from a row-major literal, e.g. matrix![1, 2, 3; 4, 5, 6].
+#[macro_export]
+macro_rules! matrix {
+    ( $( $( $val:expr ),* );* ) => {
+        {
+            let mut rows = 0usize;
+            let mut cols = 0usize;
+            let mut val = Vec::new();
+            $(
+                let mut row_len = 0usize;
+                $(
+                    val.push($val);
+                    row_len += 1;
+                )*
+                if rows == 0 {
+                    cols = row_len;
+                }
+                rows += 1;
+            )*
+            $crate::Matrix::new_with_val(rows, cols, val).unwrap()
+        }
+    };
+}
+
 impl<A: Clone> Matrix<A> {
     // Initialization
     pub fn new(rows: usize, cols: usize, val: A) -> Matrix<A> {
@@ -49,6 +87,23 @@ impl<A: Clone> Matrix<A> {
         })
     }
 
+    pub fn from_rows(rows: Vec<Vec<A>>) -> Result<Matrix<A>, &'static str> {
+        if rows.is_empty() {
+            return Err("Rows must not be empty");
+        }
+        let cols = rows[0].len();
+        if cols == 0 || !rows.iter().all(|row| row.len() == cols) {
+            return Err("All rows must have the same, non-zero length");
+        }
+        let row_count = rows.len();
+        let val = rows.into_iter().flatten().collect();
+        Ok(Matrix {
+            rows: row_count,
+            cols: cols,
+            val: val,
+        })
+    }
+
     // Indexing
     pub fn index(&self, row: usize, col: usize) -> Result<A, &'static str> {
         if row >= self.rows || col >= self.cols {
@@ -71,6 +126,26 @@ impl<A: Clone> Matrix<A> {
         (self.rows, self.cols)
     }
 
+    // Iterating
+    pub fn rows_iter(&self) -> std::slice::Chunks<'_, A> {
+        self.val.chunks(self.cols)
+    }
+
+    pub fn cols_iter(&self) -> std::vec::IntoIter<Vec<A>> {
+        let mut cols = vec![Vec::with_capacity(self.rows); self.cols];
+        for i in 0..self.rows {
+            for (j, col) in cols.iter_mut().enumerate() {
+                col.push(self.val[i * self.cols + j].clone());
+            }
+        }
+        cols.into_iter()
+    }
+
+    pub fn indices(&self) -> impl Iterator<Item = (usize, usize)> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |i| (0..cols).map(move |j| (i, j)))
+    }
+
     // Mapping
     pub fn map<F, B>(&self, f: F) -> Matrix<B>
         where F: Fn(A) -> B
@@ -112,6 +187,40 @@ impl<A: Clone> Matrix<A> {
         }
     }
 
+    // Concatenating
+    pub fn vcat(&self, other: &Matrix<A>) -> Result<Matrix<A>, &'static str> {
+        if self.cols != other.cols {
+            return Err("Both matrices need to have the same number of columns");
+        }
+        let mut val = self.val.clone();
+        val.extend(other.val.iter().cloned());
+        Ok(Matrix {
+            rows: self.rows + other.rows,
+            cols: self.cols,
+            val: val,
+        })
+    }
+
+    pub fn hcat(&self, other: &Matrix<A>) -> Result<Matrix<A>, &'static str> {
+        if self.rows != other.rows {
+            return Err("Both matrices need to have the same number of rows");
+        }
+        let mut val = Vec::with_capacity(self.rows * (self.cols + other.cols));
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                val.push(self.val[i * self.cols + j].clone());
+            }
+            for j in 0..other.cols {
+                val.push(other.val[i * other.cols + j].clone());
+            }
+        }
+        Ok(Matrix {
+            rows: self.rows,
+            cols: self.cols + other.cols,
+            val: val,
+        })
+    }
+
     // Applying
     /*pub fn apply<F: Clone, B: Clone>(&self, f: Matrix<Box<F>>) -> Result<Matrix<B>, &'static str>
         where F: Fn(A) -> B
@@ -139,9 +248,61 @@ impl<A: Clone + Num> Matrix<A> {
     pub fn scale(&self, n: A) -> Matrix<A> {
         self.map(|x| x * n.clone())
     }
+
+    pub fn elemul(&self, m: &Matrix<A>) -> Result<Matrix<A>, &'static str> {
+        self.map2(m, |x, y| x * y)
+    }
+
+    pub fn elediv(&self, m: &Matrix<A>) -> Result<Matrix<A>, &'static str> {
+        self.map2(m, |x, y| x / y)
+    }
+}
+
+impl<'b, A: Clone + Num> std::ops::Add<&'b Matrix<A>> for &Matrix<A> {
+    type Output = Matrix<A>;
+
+    fn add(self, m: &'b Matrix<A>) -> Matrix<A> {
+        self.add(m).expect("Both matricies need to have same dimensions")
+    }
+}
+
+impl<'b, A: Clone + Num> std::ops::Sub<&'b Matrix<A>> for &Matrix<A> {
+    type Output = Matrix<A>;
+
+    fn sub(self, m: &'b Matrix<A>) -> Matrix<A> {
+        self.sub(m).expect("Both matricies need to have same dimensions")
+    }
+}
+
+impl<A: Clone + Num + std::ops::Neg<Output = A>> std::ops::Neg for &Matrix<A> {
+    type Output = Matrix<A>;
+
+    fn neg(self) -> Matrix<A> {
+        self.map(|x| -x)
+    }
+}
+
+impl<A: Clone + Num> std::ops::Mul<A> for &Matrix<A> {
+    type Output = Matrix<A>;
+
+    fn mul(self, n: A) -> Matrix<A> {
+        self.scale(n)
+    }
 }
 
 impl<A: Clone + Num + std::iter::Sum> Matrix<A> {
+    pub fn sum(&self) -> A {
+        self.val.iter().cloned().sum()
+    }
+
+    pub fn row_sums(&self) -> Vec<A> {
+        self.rows_iter().map(|row| row.iter().cloned().sum()).collect()
+    }
+
+    pub fn col_sums(&self) -> Vec<A> {
+        self.cols_iter().map(|col| col.into_iter().sum()).collect()
+    }
+
     pub fn vec_mult(&self, v: &Vec<A>) -> Result<Vec<A>, &'static str> {
         if v.len() != self.cols {
             return Err("Vector length must equal matrix column length");
@@ -154,6 +315,117 @@ impl<A: Clone + Num + std::iter::Sum> Matrix<A> {
             })
             .collect())
     }
+
+    pub fn matmul(&self, other: &Matrix<A>) -> Result<Matrix<A>, &'static str> {
+        if self.cols != other.rows {
+            return Err("Left matrix column count must equal right matrix row count");
+        }
+        let mut val = Vec::with_capacity(self.rows * other.cols);
+        for i in 0..self.rows {
+            for j in 0..other.cols {
+                val.push((0..self.cols)
+                    .map(|k| self.val[i * self.cols + k].clone() * other.val[k * other.cols + j].clone())
+                    .sum());
+            }
+        }
+        Ok(Matrix {
+            rows: self.rows,
+            cols: other.cols,
+            val: val,
+        })
+    }
+
+    pub fn minor(&self, row: usize, col: usize) -> Result<Matrix<A>, &'static str> {
+        if self.rows < 2 || self.cols < 2 {
+            return Err("Matrix must be at least 2x2 to take a minor");
+        }
+        if row >= self.rows || col >= self.cols {
+            return Err("Index out of range");
+        }
+        let mut val = Vec::with_capacity((self.rows - 1) * (self.cols - 1));
+        for i in 0..self.rows {
+            if i == row {
+                continue;
+            }
+            for j in 0..self.cols {
+                if j == col {
+                    continue;
+                }
+                val.push(self.val[i * self.cols + j].clone());
+            }
+        }
+        Ok(Matrix {
+            rows: self.rows - 1,
+            cols: self.cols - 1,
+            val: val,
+        })
+    }
+
+    pub fn determinant(&self) -> Result<A, &'static str> {
+        if self.rows != self.cols {
+            return Err("Determinant is only defined for square matrices");
+        }
+        if self.rows == 1 {
+            return Ok(self.val[0].clone());
+        }
+        if self.rows == 2 {
+            return Ok(self.val[0].clone() * self.val[3].clone() -
+                       self.val[1].clone() * self.val[2].clone());
+        }
+
+        let first_minor_det = self.minor(0, 0)?.determinant()?;
+        let mut det = self.val[0].clone() * first_minor_det;
+        for j in 1..self.cols {
+            let minor_det = self.minor(0, j)?.determinant()?;
+            let term = self.val[j].clone() * minor_det;
+            if j % 2 == 0 {
+                det = det + term;
+            } else {
+                det = det - term;
+            }
+        }
+        Ok(det)
+    }
+
+    pub fn inverse(&self) -> Result<Matrix<A>, &'static str> {
+        if self.rows != self.cols {
+            return Err("Inverse is only defined for square matrices");
+        }
+        let det = self.determinant()?;
+        if det == A::zero() {
+            return Err("Matrix is singular and has no inverse");
+        }
+
+        let mut cofactor_val = Vec::with_capacity(self.rows * self.cols);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                let minor_det = if self.rows == 1 {
+                    A::one()
+                } else {
+                    self.minor(i, j)?.determinant()?
+                };
+                cofactor_val.push(if (i + j) % 2 == 0 {
+                    minor_det
+                } else {
+                    A::zero() - minor_det
+                });
+            }
+        }
+        let cofactor = Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            val: cofactor_val,
+        };
+        Ok(cofactor.transpose().scale(A::one() / det))
+    }
+}
+
+impl<'b, A: Clone + Num + std::iter::Sum> std::ops::Mul<&'b Matrix<A>> for &Matrix<A> {
+    type Output = Matrix<A>;
+
+    fn mul(self, other: &'b Matrix<A>) -> Matrix<A> {
+        self.matmul(other).expect("Matrix dimensions must be compatible for multiplication")
+    }
 }
 
 #[cfg(test)]
@@ -184,10 +456,29 @@ mod test {
         let v = vec![1, 2, 3, 4, 5, 6];
         let m = Matrix::new_with_val(2, 3, v.clone()).unwrap();
         assert_eq!(m.val, v);
-        // let m = matrix![1, 2, 3; 4, 5, 6]; the dreams that never came true
         assert!(Matrix::new_with_val(3, 3, vec![1]).is_err());
     }
 
+    #[test]
+    fn from_rows() {
+        let m = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        let m2 = Matrix::new_with_val(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(m, m2);
+
+        assert!(Matrix::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+        assert!(Matrix::from_rows(Vec::<Vec<i32>>::new()).is_err());
+    }
+
+    #[test]
+    fn matrix_macro() {
+        let m = matrix![1, 2, 3; 4, 5, 6];
+        let m2 = Matrix::new_with_val(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(m, m2);
+
+        let single_row = matrix![1, 2, 3];
+        assert_eq!(single_row, Matrix::new_with_val(1, 3, vec![1, 2, 3]).unwrap());
+    }
+
     #[test]
     fn index() {
         let m = Matrix::new_with_val(2, 2, vec![1, 2, 3, 4]).unwrap();
@@ -212,6 +503,37 @@ mod test {
         assert_eq!(Matrix::new(22, 43, 0).dimensions(), (22, 43));
     }
 
+    #[test]
+    fn index_operator() {
+        let mut m = Matrix::new_with_val(2, 2, vec![1, 2, 3, 4]).unwrap();
+        assert_eq!(m[(0, 0)], 1);
+        assert_eq!(m[(1, 1)], 4);
+
+        m[(0, 1)] = 42;
+        assert_eq!(m[(0, 1)], 42);
+    }
+
+    #[test]
+    fn rows_iter() {
+        let m = Matrix::new_with_val(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let rows: Vec<&[i32]> = m.rows_iter().collect();
+        assert_eq!(rows, vec![&[1, 2, 3][..], &[4, 5, 6][..]]);
+    }
+
+    #[test]
+    fn cols_iter() {
+        let m = Matrix::new_with_val(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let cols: Vec<Vec<i32>> = m.cols_iter().collect();
+        assert_eq!(cols, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    }
+
+    #[test]
+    fn indices() {
+        let m = Matrix::new(2, 2, 0);
+        let idx: Vec<(usize, usize)> = m.indices().collect();
+        assert_eq!(idx, vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+    }
+
     #[test]
     fn map() {
         let m = Matrix::new_with_val(2, 2, vec![1, 2, 3, 4]).unwrap();
@@ -264,6 +586,74 @@ mod test {
         assert_eq!(m2, m3);
     }
 
+    #[test]
+    fn elemul() {
+        let m = Matrix::new_with_val(2, 2, vec![1, 2, 3, 4]).unwrap();
+        let m2 = Matrix::new_with_val(2, 2, vec![1, 2, 3, 4]).unwrap();
+        let m3 = m.elemul(&m2).unwrap();
+        let m4 = Matrix::new_with_val(2, 2, vec![1, 4, 9, 16]).unwrap();
+        assert_eq!(m3, m4);
+
+        let m5 = Matrix::new(3, 3, 0);
+        assert!(m.elemul(&m5).is_err());
+    }
+
+    #[test]
+    fn elediv() {
+        let m = Matrix::new_with_val(2, 2, vec![2, 9, 12, 20]).unwrap();
+        let m2 = Matrix::new_with_val(2, 2, vec![2, 3, 4, 5]).unwrap();
+        let m3 = m.elediv(&m2).unwrap();
+        let m4 = Matrix::new_with_val(2, 2, vec![1, 3, 3, 4]).unwrap();
+        assert_eq!(m3, m4);
+
+        let m5 = Matrix::new(3, 3, 1);
+        assert!(m.elediv(&m5).is_err());
+    }
+
+    #[test]
+    fn sum() {
+        let m = Matrix::new_with_val(2, 2, vec![1, 2, 3, 4]).unwrap();
+        assert_eq!(m.sum(), 10);
+    }
+
+    #[test]
+    fn row_sums() {
+        let m = Matrix::new_with_val(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(m.row_sums(), vec![6, 15]);
+    }
+
+    #[test]
+    fn col_sums() {
+        let m = Matrix::new_with_val(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(m.col_sums(), vec![5, 7, 9]);
+    }
+
+    #[test]
+    fn operators() {
+        let m = Matrix::new_with_val(2, 2, vec![1, 2, 3, 4]).unwrap();
+        let m2 = Matrix::new_with_val(2, 2, vec![1, 2, 3, 4]).unwrap();
+
+        let sum = &m + &m2;
+        assert_eq!(sum, Matrix::new_with_val(2, 2, vec![2, 4, 6, 8]).unwrap());
+
+        let diff = &m - &m2;
+        assert_eq!(diff, Matrix::new(2, 2, 0));
+
+        let neg = -&m;
+        assert_eq!(neg, Matrix::new_with_val(2, 2, vec![-1, -2, -3, -4]).unwrap());
+
+        let scaled = &m * 2;
+        assert_eq!(scaled, Matrix::new_with_val(2, 2, vec![2, 4, 6, 8]).unwrap());
+    }
+
+    #[test]
+    #[should_panic]
+    fn operators_mismatched_dimensions_panic() {
+        let m = Matrix::new_with_val(2, 2, vec![1, 2, 3, 4]).unwrap();
+        let m2 = Matrix::new(3, 3, 0);
+        let _ = &m + &m2;
+    }
+
     #[test]
     fn vec_mult() {
         let m = Matrix::new(3, 4, 3);
@@ -273,6 +663,65 @@ mod test {
         assert_eq!(v2, v3);
     }
 
+    #[test]
+    fn matmul() {
+        let m = Matrix::new_with_val(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let m2 = Matrix::new_with_val(3, 2, vec![7, 8, 9, 10, 11, 12]).unwrap();
+        let m3 = m.matmul(&m2).unwrap();
+        let m4 = Matrix::new_with_val(2, 2, vec![58, 64, 139, 154]).unwrap();
+        assert_eq!(m3, m4);
+
+        let m5 = &m * &m2;
+        assert_eq!(m5, m4);
+
+        let m6 = Matrix::new(2, 2, 0);
+        assert!(m.matmul(&m6).is_err());
+    }
+
+    #[test]
+    fn minor() {
+        let m = Matrix::new_with_val(3, 3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]).unwrap();
+        let m2 = m.minor(1, 1).unwrap();
+        let m3 = Matrix::new_with_val(2, 2, vec![1, 3, 7, 9]).unwrap();
+        assert_eq!(m2, m3);
+
+        assert!(m.minor(5, 5).is_err());
+
+        let too_small = Matrix::new(1, 1, 0);
+        assert!(too_small.minor(0, 0).is_err());
+    }
+
+    #[test]
+    fn determinant() {
+        let m = Matrix::new_with_val(1, 1, vec![5]).unwrap();
+        assert_eq!(m.determinant().unwrap(), 5);
+
+        let m2 = Matrix::new_with_val(2, 2, vec![1, 2, 3, 4]).unwrap();
+        assert_eq!(m2.determinant().unwrap(), -2);
+
+        let m3 = Matrix::new_with_val(3, 3, vec![6, 1, 1, 4, -2, 5, 2, 8, 7]).unwrap();
+        assert_eq!(m3.determinant().unwrap(), -306);
+
+        let m4 = Matrix::new(2, 3, 0);
+        assert!(m4.determinant().is_err());
+    }
+
+    #[test]
+    fn inverse() {
+        let m = Matrix::new_with_val(2, 2, vec![4.0, 7.0, 2.0, 6.0]).unwrap();
+        let inv = m.inverse().unwrap();
+        let expected: Vec<f64> = vec![0.6, -0.7, -0.2, 0.4];
+        for (got, want) in inv.val.iter().zip(expected.iter()) {
+            assert!((got - want).abs() < 1e-10);
+        }
+
+        let singular = Matrix::new_with_val(2, 2, vec![1.0, 2.0, 2.0, 4.0]).unwrap();
+        assert!(singular.inverse().is_err());
+
+        let non_square = Matrix::new(2, 3, 0.0);
+        assert!(non_square.inverse().is_err());
+    }
+
     #[test]
     fn transpose() {
         let m = Matrix::new_with_val(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
@@ -282,6 +731,30 @@ mod test {
         assert_eq!(m2, m3);
     }
 
+    #[test]
+    fn vcat() {
+        let m = Matrix::new_with_val(1, 2, vec![1, 2]).unwrap();
+        let m2 = Matrix::new_with_val(2, 2, vec![3, 4, 5, 6]).unwrap();
+        let m3 = m.vcat(&m2).unwrap();
+        let m4 = Matrix::new_with_val(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(m3, m4);
+
+        let m5 = Matrix::new(2, 3, 0);
+        assert!(m.vcat(&m5).is_err());
+    }
+
+    #[test]
+    fn hcat() {
+        let m = Matrix::new_with_val(2, 1, vec![1, 3]).unwrap();
+        let m2 = Matrix::new_with_val(2, 2, vec![2, 4, 5, 6]).unwrap();
+        let m3 = m.hcat(&m2).unwrap();
+        let m4 = Matrix::new_with_val(2, 3, vec![1, 2, 4, 3, 5, 6]).unwrap();
+        assert_eq!(m3, m4);
+
+        let m5 = Matrix::new(3, 2, 0);
+        assert!(m.hcat(&m5).is_err());
+    }
+
     /* Closures pls
     #[test]
     fn apply() {